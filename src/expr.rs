@@ -609,6 +609,31 @@ ast_struct! {
     }
 }
 
+impl<T> From<T> for ExprPath
+where
+    T: Into<Path>,
+{
+    fn from(path: T) -> Self {
+        ExprPath {
+            attrs: Vec::new(),
+            qself: None,
+            path: path.into(),
+        }
+    }
+}
+
+impl From<Path> for Expr {
+    fn from(path: Path) -> Self {
+        Expr::Path(ExprPath::from(path))
+    }
+}
+
+impl From<Ident> for Expr {
+    fn from(ident: Ident) -> Self {
+        Expr::Path(ExprPath::from(ident))
+    }
+}
+
 ast_struct! {
     /// A range expression: `1..2`, `1..`, `..2`, `1..=2`, `..=2`.
     ///
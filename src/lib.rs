@@ -810,8 +810,9 @@ mod reserved;
 #[cfg(all(any(feature = "full", feature = "derive"), feature = "parsing"))]
 mod verbatim;
 
-#[cfg(all(any(feature = "full", feature = "derive"), feature = "printing"))]
-mod print;
+#[cfg(feature = "printing")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "printing")))]
+pub mod print;
 
 ////////////////////////////////////////////////////////////////////////////////
 
@@ -1,6 +1,39 @@
+//! Helpers for implementing `ToTokens` on syntax tree nodes.
+//!
+//! *This module is available only if Syn is built with the `"printing"`
+//! feature.*
+
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 
+/// Prints an optional token, or the default value of its type if absent.
+///
+/// This is the pattern used throughout Syn's own printing code for tokens
+/// that are optional in the syntax tree but required in the printed output,
+/// such as the `<` and `>` around generic parameters.
+///
+/// # Example
+///
+/// ```
+/// use proc_macro2::TokenStream;
+/// use quote::ToTokens;
+/// use syn::print::TokensOrDefault;
+/// use syn::{Ident, Token, Type};
+///
+/// struct Field {
+///     ident: Ident,
+///     colon_token: Option<Token![:]>,
+///     ty: Type,
+/// }
+///
+/// impl ToTokens for Field {
+///     fn to_tokens(&self, tokens: &mut TokenStream) {
+///         self.ident.to_tokens(tokens);
+///         TokensOrDefault(&self.colon_token).to_tokens(tokens);
+///         self.ty.to_tokens(tokens);
+///     }
+/// }
+/// ```
 pub struct TokensOrDefault<'a, T: 'a>(pub &'a Option<T>);
 
 impl<'a, T> ToTokens for TokensOrDefault<'a, T>
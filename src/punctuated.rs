@@ -70,6 +70,8 @@ impl<T, P> Punctuated<T, P> {
 
     /// Determines whether this punctuated sequence is empty, meaning it
     /// contains no syntax tree nodes or punctuation.
+    ///
+    /// This operation runs in constant time.
     pub fn is_empty(&self) -> bool {
         self.inner.len() == 0 && self.last.is_none()
     }
@@ -77,7 +79,7 @@ impl<T, P> Punctuated<T, P> {
     /// Returns the number of syntax tree nodes in this punctuated sequence.
     ///
     /// This is the number of nodes of type `T`, not counting the punctuation of
-    /// type `P`.
+    /// type `P`. This operation runs in constant time.
     pub fn len(&self) -> usize {
         self.inner.len() + if self.last.is_some() { 1 } else { 0 }
     }
@@ -102,6 +104,31 @@ impl<T, P> Punctuated<T, P> {
         self.iter_mut().next_back()
     }
 
+    /// Borrows the element at the given index, or `None` if the index is out
+    /// of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if let Some((value, _punct)) = self.inner.get(index) {
+            Some(value)
+        } else if index == self.inner.len() {
+            self.last.as_ref().map(Box::as_ref)
+        } else {
+            None
+        }
+    }
+
+    /// Mutably borrows the element at the given index, or `None` if the index
+    /// is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let inner_len = self.inner.len();
+        if let Some((value, _punct)) = self.inner.get_mut(index) {
+            Some(value)
+        } else if index == inner_len {
+            self.last.as_mut().map(Box::as_mut)
+        } else {
+            None
+        }
+    }
+
     /// Returns an iterator over borrowed syntax tree nodes of type `&T`.
     pub fn iter(&self) -> Iter<T> {
         Iter {
@@ -198,6 +225,32 @@ impl<T, P> Punctuated<T, P> {
         }
     }
 
+    /// Removes the trailing punctuation from this punctuated sequence, or
+    /// `None` if there isn't any.
+    ///
+    /// Together with [`push_punct`], this allows normalizing whether a
+    /// sequence is printed with a trailing punctuation:
+    ///
+    /// ```
+    /// # use syn::punctuated::Punctuated;
+    /// # use syn::{parse_quote, Expr, Token};
+    /// #
+    /// let mut args: Punctuated<Expr, Token![,]> = parse_quote!(a, b, c,);
+    /// args.pop_punct();
+    /// assert!(!args.trailing_punct());
+    /// ```
+    ///
+    /// [`push_punct`]: Punctuated::push_punct
+    pub fn pop_punct(&mut self) -> Option<P> {
+        if self.last.is_some() {
+            None
+        } else {
+            let (t, p) = self.inner.pop()?;
+            self.last = Some(Box::new(t));
+            Some(p)
+        }
+    }
+
     /// Determines whether this punctuated sequence ends with a trailing
     /// punctuation.
     pub fn trailing_punct(&self) -> bool {
@@ -255,6 +308,28 @@ impl<T, P> Punctuated<T, P> {
         self.last = None;
     }
 
+    /// Retains only the elements for which the predicate returns true,
+    /// removing the others together with their following punctuation.
+    ///
+    /// Elements are visited in order. The sequence keeps a trailing
+    /// punctuation afterward if and only if it had one before, unless it
+    /// becomes empty.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.inner.retain(|(t, _p)| f(t));
+        match &self.last {
+            Some(last) if !f(last) => {
+                self.last = None;
+                if let Some((t, _p)) = self.inner.pop() {
+                    self.last = Some(Box::new(t));
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Parses zero or more occurrences of `T` separated by punctuation of type
     /// `P`, with optional trailing punctuation.
     ///
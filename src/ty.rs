@@ -203,6 +203,30 @@ ast_struct! {
     }
 }
 
+impl<T> From<T> for TypePath
+where
+    T: Into<Path>,
+{
+    fn from(path: T) -> Self {
+        TypePath {
+            qself: None,
+            path: path.into(),
+        }
+    }
+}
+
+impl From<Path> for Type {
+    fn from(path: Path) -> Self {
+        Type::Path(TypePath::from(path))
+    }
+}
+
+impl From<Ident> for Type {
+    fn from(ident: Ident) -> Self {
+        Type::Path(TypePath::from(ident))
+    }
+}
+
 ast_struct! {
     /// A raw pointer type: `*const T` or `*mut T`.
     ///
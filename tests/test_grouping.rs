@@ -2,6 +2,7 @@
 mod macros;
 
 use proc_macro2::{Delimiter, Group, Literal, Punct, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
 use std::iter::FromIterator;
 use syn::Expr;
 
@@ -50,3 +51,32 @@ fn test_grouping() {
     }
     "###);
 }
+
+#[test]
+fn test_grouping_round_trip() {
+    let tokens: TokenStream = TokenStream::from_iter(vec![
+        TokenTree::Group(Group::new(
+            Delimiter::None,
+            TokenStream::from_iter(vec![
+                TokenTree::Literal(Literal::i32_suffixed(1)),
+                TokenTree::Punct(Punct::new('+', Spacing::Alone)),
+                TokenTree::Literal(Literal::i32_suffixed(2)),
+            ]),
+        )),
+        TokenTree::Punct(Punct::new('*', Spacing::Alone)),
+        TokenTree::Literal(Literal::i32_suffixed(3)),
+    ]);
+
+    let expr: Expr = syn::parse2(tokens).unwrap();
+    let printed = expr.into_token_stream();
+
+    let mut iter = printed.into_iter();
+    match iter.next() {
+        Some(TokenTree::Group(group)) => {
+            assert_eq!(group.delimiter(), Delimiter::None);
+            assert_eq!(group.stream().to_string(), "1i32 + 2i32");
+        }
+        other => panic!("expected None-delimited group, found {:?}", other),
+    }
+    assert_eq!(iter.collect::<TokenStream>().to_string(), "* 3i32");
+}
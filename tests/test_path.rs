@@ -124,3 +124,28 @@ fn parse_parenthesized_path_arguments_with_disambiguator() {
     }
     "###);
 }
+
+#[test]
+fn from_ident() {
+    let ident = Ident::new("T", Span::call_site());
+
+    let ty = Type::from(ident.clone());
+    assert_eq!(ty.to_token_stream().to_string(), "T");
+    let ty_path = TypePath::from(ident.clone());
+    assert!(ty_path.qself.is_none());
+    assert!(ty_path.path.is_ident("T"));
+
+    let expr = Expr::from(ident);
+    snapshot!(expr, @r###"
+    Expr::Path {
+        path: Path {
+            segments: [
+                PathSegment {
+                    ident: "T",
+                    arguments: None,
+                },
+            ],
+        },
+    }
+    "###);
+}
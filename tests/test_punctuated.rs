@@ -0,0 +1,59 @@
+use syn::punctuated::Punctuated;
+use syn::Token;
+
+#[macro_use]
+mod macros;
+
+#[test]
+fn retain() {
+    let mut p: Punctuated<_, Token![,]> = punctuated!(2, 3, 4, 5);
+    p.retain(|n| n % 2 == 0);
+    assert_eq!(p.iter().collect::<Vec<_>>(), [&2, &4]);
+    assert!(!p.trailing_punct());
+
+    let mut p: Punctuated<_, Token![,]> = punctuated!(2, 3, 4, 5);
+    p.retain(|n| n % 2 == 1);
+    assert_eq!(p.iter().collect::<Vec<_>>(), [&3, &5]);
+    assert!(!p.trailing_punct());
+
+    let mut p: Punctuated<_, Token![,]> = punctuated!(2, 3, 4, 5);
+    p.push_punct(<Token![,]>::default());
+    p.retain(|n| n % 2 == 0);
+    assert_eq!(p.iter().collect::<Vec<_>>(), [&2, &4]);
+    assert!(p.trailing_punct());
+
+    let mut p: Punctuated<_, Token![,]> = punctuated!(2, 3, 4, 5);
+    p.retain(|_| false);
+    assert!(p.is_empty());
+}
+
+#[test]
+fn get() {
+    let mut p: Punctuated<_, Token![,]> = punctuated!(2, 3, 4);
+    assert_eq!(p.get(0), Some(&2));
+    assert_eq!(p.get(2), Some(&4));
+    assert_eq!(p.get(3), None);
+    assert_eq!(p[1], 3);
+
+    *p.get_mut(2).unwrap() = 5;
+    p[0] = 1;
+    assert_eq!(p.iter().collect::<Vec<_>>(), [&1, &3, &5]);
+
+    p.push_punct(<Token![,]>::default());
+    assert_eq!(p.get(2), Some(&5));
+    assert_eq!(p.get_mut(3), None);
+}
+
+#[test]
+fn pop_punct() {
+    let mut p: Punctuated<_, Token![,]> = punctuated!(2, 3);
+    assert!(p.pop_punct().is_none());
+
+    p.push_punct(<Token![,]>::default());
+    assert!(p.pop_punct().is_some());
+    assert!(!p.trailing_punct());
+    assert_eq!(p.iter().collect::<Vec<_>>(), [&2, &3]);
+
+    let mut p = Punctuated::<i32, Token![,]>::new();
+    assert!(p.pop_punct().is_none());
+}
@@ -91,3 +91,28 @@ fn test_let_dot_dot() {
     })
     "###);
 }
+
+#[test]
+fn test_contextual_keyword_variables() {
+    let tokens = quote! {
+        let union = default;
+    };
+
+    snapshot!(tokens as Stmt, @r###"
+    Local(Local {
+        pat: Pat::Ident {
+            ident: "union",
+        },
+        init: Some(Expr::Path {
+            path: Path {
+                segments: [
+                    PathSegment {
+                        ident: "default",
+                        arguments: None,
+                    },
+                ],
+            },
+        }),
+    })
+    "###);
+}